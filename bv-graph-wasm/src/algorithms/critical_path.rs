@@ -3,6 +3,7 @@
 //! Computes the longest dependency chain from roots to each node.
 //! Nodes with high heights are deep in the dependency tree.
 
+use crate::algorithms::cycles::condensation;
 use crate::algorithms::topo::topological_sort;
 use crate::graph::DiGraph;
 
@@ -22,10 +23,10 @@ pub fn critical_path_heights(graph: &DiGraph) -> Vec<f64> {
         return Vec::new();
     }
 
-    // Topological order (returns None if cyclic)
+    // Topological order (returns the blocked/cyclic remainder on failure)
     let order = match topological_sort(graph) {
-        Some(o) => o,
-        None => return vec![0.0; n], // Return zeros for cyclic graphs
+        Ok(o) => o,
+        Err(_) => return vec![0.0; n], // Return zeros for cyclic graphs
     };
 
     let mut heights = vec![0.0; n];
@@ -68,6 +69,264 @@ pub fn critical_path_length(graph: &DiGraph) -> f64 {
         .fold(0.0, f64::max)
 }
 
+/// Compute critical-path heights on a possibly-cyclic graph.
+///
+/// Collapses each strongly connected component into a single super-node via
+/// `condensation`, runs the ordinary longest-path recurrence on that
+/// (always-acyclic) DAG, then projects each component's height back onto
+/// every original node it contains. Unlike `critical_path_heights`, this
+/// never bails out to all-zeros on a cycle — tangled issues simply share
+/// the height of their component.
+pub fn critical_path_heights_condensed(graph: &DiGraph) -> Vec<f64> {
+    critical_path_heights_condensed_with_components(graph).0
+}
+
+/// Like `critical_path_heights_condensed`, but also returns which SCC each
+/// original node belongs to (nodes sharing a component id are mutually
+/// reachable, i.e. tangled together by a cycle).
+pub fn critical_path_heights_condensed_with_components(graph: &DiGraph) -> (Vec<f64>, Vec<usize>) {
+    if graph.len() == 0 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let cond = condensation(graph);
+    let component_heights = critical_path_heights(&cond.dag);
+
+    let heights = cond
+        .component_of
+        .iter()
+        .map(|&c| component_heights[c])
+        .collect();
+
+    (heights, cond.component_of)
+}
+
+/// Weighted critical-path result: earliest/latest finish times, slack, and
+/// the longest (zero-slack) chain through the graph.
+pub struct CriticalPathResult {
+    /// Earliest finish time for each node.
+    pub earliest: Vec<f64>,
+    /// Latest finish time for each node without delaying the project.
+    pub latest: Vec<f64>,
+    /// Slack (latest - earliest) for each node; zero means on the critical path.
+    pub slack: Vec<f64>,
+    /// Nodes on the longest (critical) path, in topological order.
+    pub path: Vec<usize>,
+    /// Length of the critical path (the project makespan).
+    pub length: f64,
+}
+
+/// Compute the weighted critical path over a DAG.
+///
+/// Runs a forward pass in topological order to find each node's earliest
+/// finish time (`max over predecessors + node weight`), then a backward
+/// pass in reverse topological order to find the latest finish time each
+/// node can have without delaying the makespan. Slack is the gap between
+/// the two; the critical path follows the chain of zero-slack nodes.
+///
+/// # Arguments
+/// * `graph` - The directed graph
+/// * `weights` - Per-node duration, indexed by node id; defaults to 1.0 for
+///   every node when `None`
+///
+/// # Returns
+/// `None` if the graph contains a cycle (callers should break cycles first,
+/// e.g. via `feedback_arc_set`), otherwise the timing and slack vectors plus
+/// the longest path and its length.
+pub fn critical_path(graph: &DiGraph, weights: Option<&[f64]>) -> Option<CriticalPathResult> {
+    let n = graph.len();
+    if n == 0 {
+        return Some(CriticalPathResult {
+            earliest: Vec::new(),
+            latest: Vec::new(),
+            slack: Vec::new(),
+            path: Vec::new(),
+            length: 0.0,
+        });
+    }
+
+    let order = topological_sort(graph).ok()?;
+
+    let default_weights;
+    let weight: &[f64] = match weights {
+        Some(w) => w,
+        None => {
+            default_weights = vec![1.0; n];
+            &default_weights
+        }
+    };
+
+    // Forward pass: earliest finish time.
+    let mut earliest = vec![0.0; n];
+    for &v in &order {
+        let max_pred = graph
+            .predecessors_slice(v)
+            .iter()
+            .map(|&u| earliest[u])
+            .fold(0.0, f64::max);
+        earliest[v] = max_pred + weight[v];
+    }
+
+    let length = earliest.iter().cloned().fold(0.0, f64::max);
+
+    // Backward pass: latest finish time (sinks are seeded to the makespan).
+    let mut latest = vec![length; n];
+    for &v in order.iter().rev() {
+        let min_succ = graph
+            .successors_slice(v)
+            .iter()
+            .map(|&s| latest[s] - weight[s])
+            .fold(f64::INFINITY, f64::min);
+        if min_succ.is_finite() {
+            latest[v] = min_succ;
+        }
+    }
+
+    let slack: Vec<f64> = earliest
+        .iter()
+        .zip(latest.iter())
+        .map(|(&e, &l)| l - e)
+        .collect();
+
+    // Reconstruct one longest path by walking backward from a node that
+    // reaches the makespan, following zero-slack predecessors.
+    let mut path = Vec::new();
+    if let Some(&end) = order
+        .iter()
+        .rev()
+        .find(|&&v| (earliest[v] - length).abs() < 1e-9)
+    {
+        let mut current = end;
+        path.push(current);
+        loop {
+            let next = graph
+                .predecessors_slice(current)
+                .iter()
+                .copied()
+                .find(|&u| (earliest[u] + weight[current] - earliest[current]).abs() < 1e-9);
+            match next {
+                Some(u) => {
+                    path.push(u);
+                    current = u;
+                }
+                None => break,
+            }
+        }
+        path.reverse();
+    }
+
+    Some(CriticalPathResult {
+        earliest,
+        latest,
+        slack,
+        path,
+        length,
+    })
+}
+
+/// Full CPM/PERT schedule: earliest/latest start and finish times, total
+/// slack, and a critical-path flag for every node.
+pub struct CpmSchedule {
+    /// Earliest a node can start, given its predecessors.
+    pub earliest_start: Vec<f64>,
+    /// Earliest a node can finish (`earliest_start + duration`).
+    pub earliest_finish: Vec<f64>,
+    /// Latest a node can start without delaying the project.
+    pub latest_start: Vec<f64>,
+    /// Latest a node can finish without delaying the project.
+    pub latest_finish: Vec<f64>,
+    /// Total slack (`latest_start - earliest_start`) for each node.
+    pub slack: Vec<f64>,
+    /// Whether each node has zero slack (lies on the critical path).
+    pub critical: Vec<bool>,
+}
+
+/// Compute a full CPM/PERT schedule over a DAG of tasks with durations.
+///
+/// Forward pass (topological order): `earliest_start[v] = max over
+/// predecessors of earliest_finish`, `earliest_finish[v] = earliest_start[v]
+/// + duration[v]`. Backward pass (reverse topological order):
+/// `latest_finish[v] = min over successors of latest_start`, seeded to the
+/// project makespan for sink nodes, then `latest_start[v] = latest_finish[v]
+/// - duration[v]`. Nodes with zero slack form the critical path.
+///
+/// # Arguments
+/// * `graph` - The directed graph of tasks
+/// * `durations` - Per-node duration, indexed by node id; defaults to 1.0
+///   for every node when `None`
+///
+/// # Returns
+/// `None` if the graph contains a cycle, otherwise the full schedule.
+pub fn cpm_schedule(graph: &DiGraph, durations: Option<&[f64]>) -> Option<CpmSchedule> {
+    let n = graph.len();
+    if n == 0 {
+        return Some(CpmSchedule {
+            earliest_start: Vec::new(),
+            earliest_finish: Vec::new(),
+            latest_start: Vec::new(),
+            latest_finish: Vec::new(),
+            slack: Vec::new(),
+            critical: Vec::new(),
+        });
+    }
+
+    let order = topological_sort(graph).ok()?;
+
+    let default_durations;
+    let duration: &[f64] = match durations {
+        Some(d) => d,
+        None => {
+            default_durations = vec![1.0; n];
+            &default_durations
+        }
+    };
+
+    // Forward pass: earliest start/finish.
+    let mut earliest_start = vec![0.0; n];
+    let mut earliest_finish = vec![0.0; n];
+    for &v in &order {
+        earliest_start[v] = graph
+            .predecessors_slice(v)
+            .iter()
+            .map(|&u| earliest_finish[u])
+            .fold(0.0, f64::max);
+        earliest_finish[v] = earliest_start[v] + duration[v];
+    }
+
+    let makespan = earliest_finish.iter().cloned().fold(0.0, f64::max);
+
+    // Backward pass: latest start/finish (sinks seeded to the makespan).
+    let mut latest_start = vec![0.0; n];
+    let mut latest_finish = vec![makespan; n];
+    for &v in order.iter().rev() {
+        let min_succ = graph
+            .successors_slice(v)
+            .iter()
+            .map(|&w| latest_start[w])
+            .fold(f64::INFINITY, f64::min);
+        if min_succ.is_finite() {
+            latest_finish[v] = min_succ;
+        }
+        latest_start[v] = latest_finish[v] - duration[v];
+    }
+
+    let slack: Vec<f64> = latest_start
+        .iter()
+        .zip(earliest_start.iter())
+        .map(|(&ls, &es)| ls - es)
+        .collect();
+    let critical: Vec<bool> = slack.iter().map(|&s| s.abs() < 1e-9).collect();
+
+    Some(CpmSchedule {
+        earliest_start,
+        earliest_finish,
+        latest_start,
+        latest_finish,
+        slack,
+        critical,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,4 +482,208 @@ mod tests {
         assert_eq!(heights[d], 2.0);
         assert_eq!(heights[e], 2.0);
     }
+
+    // === Weighted Critical Path Tests ===
+
+    #[test]
+    fn test_critical_path_empty() {
+        let g = DiGraph::new();
+        let result = critical_path(&g, None).unwrap();
+        assert!(result.earliest.is_empty());
+        assert_eq!(result.length, 0.0);
+        assert!(result.path.is_empty());
+    }
+
+    #[test]
+    fn test_critical_path_unit_weights_matches_heights() {
+        // a -> b -> c, default weights should mirror critical_path_heights
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        let result = critical_path(&g, None).unwrap();
+        assert_eq!(result.earliest, vec![1.0, 2.0, 3.0]);
+        assert_eq!(result.length, 3.0);
+        assert_eq!(result.path, vec![a, b, c]);
+        assert_eq!(result.slack, vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_critical_path_weighted_diamond() {
+        //     a (w=1)
+        //    / \
+        //   b   c   b: w=5, c: w=1
+        //    \ /
+        //     d (w=1)
+        // a->b->d is the long chain (1+5+1=7), a->c->d is short (1+1+1=3)
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        let weights = vec![1.0, 5.0, 1.0, 1.0];
+        let result = critical_path(&g, Some(&weights)).unwrap();
+
+        assert_eq!(result.length, 7.0);
+        assert_eq!(result.path, vec![a, b, d]);
+        // The short chain through c has slack equal to the difference in path weight.
+        assert_eq!(result.slack[b], 0.0);
+        assert!(result.slack[c] > 0.0);
+    }
+
+    #[test]
+    fn test_critical_path_cyclic_returns_none() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+
+        assert!(critical_path(&g, None).is_none());
+    }
+
+    #[test]
+    fn test_critical_path_parallel_chains_slack() {
+        // a -> b -> c (length 3, critical)
+        // d -> e      (length 2, slack 1)
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        let e = g.add_node("e");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(d, e);
+
+        let result = critical_path(&g, None).unwrap();
+        assert_eq!(result.length, 3.0);
+        assert_eq!(result.slack[a], 0.0);
+        assert_eq!(result.slack[b], 0.0);
+        assert_eq!(result.slack[c], 0.0);
+        assert_eq!(result.slack[d], 1.0);
+        assert_eq!(result.slack[e], 1.0);
+    }
+
+    // === CPM/PERT Schedule Tests ===
+
+    #[test]
+    fn test_cpm_schedule_empty() {
+        let g = DiGraph::new();
+        let schedule = cpm_schedule(&g, None).unwrap();
+        assert!(schedule.earliest_start.is_empty());
+        assert!(schedule.critical.is_empty());
+    }
+
+    #[test]
+    fn test_cpm_schedule_linear_chain_all_critical() {
+        // a -> b -> c, unit durations: every node is on the critical path
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        let schedule = cpm_schedule(&g, None).unwrap();
+        assert_eq!(schedule.earliest_start, vec![0.0, 1.0, 2.0]);
+        assert_eq!(schedule.earliest_finish, vec![1.0, 2.0, 3.0]);
+        assert_eq!(schedule.latest_start, vec![0.0, 1.0, 2.0]);
+        assert_eq!(schedule.latest_finish, vec![1.0, 2.0, 3.0]);
+        assert_eq!(schedule.slack, vec![0.0, 0.0, 0.0]);
+        assert_eq!(schedule.critical, vec![true, true, true]);
+    }
+
+    #[test]
+    fn test_cpm_schedule_weighted_diamond_slack() {
+        // a -> b -> d (b: duration 5, long chain)
+        // a -> c -> d (c: duration 1, has slack)
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+        g.add_edge(b, d);
+        g.add_edge(c, d);
+
+        let durations = vec![1.0, 5.0, 1.0, 1.0];
+        let schedule = cpm_schedule(&g, Some(&durations)).unwrap();
+
+        assert_eq!(schedule.earliest_finish[d], 7.0);
+        assert!(schedule.critical[a]);
+        assert!(schedule.critical[b]);
+        assert!(schedule.critical[d]);
+        assert!(!schedule.critical[c]);
+        assert_eq!(schedule.slack[c], 4.0);
+    }
+
+    #[test]
+    fn test_cpm_schedule_cyclic_returns_none() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+
+        assert!(cpm_schedule(&g, None).is_none());
+    }
+
+    // === Condensed Critical Path Tests ===
+
+    #[test]
+    fn test_condensed_heights_acyclic_matches_plain() {
+        // a -> b -> c, no cycles: condensed heights should match the plain ones
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        let plain = critical_path_heights(&g);
+        let condensed = critical_path_heights_condensed(&g);
+        assert_eq!(plain, condensed);
+    }
+
+    #[test]
+    fn test_condensed_heights_cyclic_is_nonzero() {
+        // a -> b -> c -> a (cycle), d depends on the whole SCC
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, a);
+        g.add_edge(c, d);
+
+        // Plain heights bail out to all zeros on a cycle.
+        assert_eq!(critical_path_heights(&g), vec![0.0, 0.0, 0.0, 0.0]);
+
+        let (heights, components) = critical_path_heights_condensed_with_components(&g);
+        // a, b, c are tangled in the same SCC and share a height.
+        assert_eq!(components[a], components[b]);
+        assert_eq!(components[b], components[c]);
+        assert_eq!(heights[a], heights[b]);
+        assert_eq!(heights[b], heights[c]);
+        // d depends on the cycle's component, so it's strictly deeper.
+        assert!(heights[d] > heights[a]);
+    }
+
+    #[test]
+    fn test_condensed_heights_empty() {
+        let g = DiGraph::new();
+        assert!(critical_path_heights_condensed(&g).is_empty());
+    }
 }
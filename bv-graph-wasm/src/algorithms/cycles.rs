@@ -19,10 +19,22 @@ pub struct SCCResult {
     pub cycle_count: usize,
 }
 
+/// One frame of the explicit `strongconnect` work-stack used by [`tarjan_scc`].
+///
+/// Mirrors the locals of the recursive formulation: `node` is the vertex
+/// being visited and `succ_idx` is how far through its successor slice we've
+/// advanced, so a resumed frame can pick up exactly where it left off.
+struct TarjanFrame {
+    node: usize,
+    succ_idx: usize,
+}
+
 /// Tarjan's algorithm for finding strongly connected components.
 ///
-/// An SCC with more than one node indicates a cycle.
-/// Complexity: O(V + E)
+/// An SCC with more than one node indicates a cycle. Uses an explicit
+/// work-stack rather than recursion so it stays in bounded heap memory on
+/// graphs with very long dependency chains (deep recursion would otherwise
+/// overflow the native stack). Complexity: O(V + E)
 pub fn tarjan_scc(graph: &DiGraph) -> SCCResult {
     let n = graph.len();
     if n == 0 {
@@ -37,63 +49,74 @@ pub fn tarjan_scc(graph: &DiGraph) -> SCCResult {
     let mut indices = vec![usize::MAX; n];
     let mut lowlink = vec![usize::MAX; n];
     let mut on_stack = vec![false; n];
-    let mut stack: Vec<usize> = Vec::new();
+    let mut scc_stack: Vec<usize> = Vec::new();
     let mut components: Vec<Vec<usize>> = Vec::new();
 
-    fn strongconnect(
-        v: usize,
-        graph: &DiGraph,
-        index: &mut usize,
-        indices: &mut [usize],
-        lowlink: &mut [usize],
-        on_stack: &mut [bool],
-        stack: &mut Vec<usize>,
-        components: &mut Vec<Vec<usize>>,
-    ) {
-        indices[v] = *index;
-        lowlink[v] = *index;
-        *index += 1;
-        stack.push(v);
-        on_stack[v] = true;
+    let mut work: Vec<TarjanFrame> = Vec::new();
 
-        for &w in graph.successors_slice(v) {
-            if indices[w] == usize::MAX {
-                // Not visited
-                strongconnect(w, graph, index, indices, lowlink, on_stack, stack, components);
-                lowlink[v] = lowlink[v].min(lowlink[w]);
-            } else if on_stack[w] {
-                // On stack = in current SCC
-                lowlink[v] = lowlink[v].min(indices[w]);
-            }
-        }
-
-        // If v is a root node, pop the stack to get SCC
-        if lowlink[v] == indices[v] {
-            let mut component = Vec::new();
-            loop {
-                let w = stack.pop().unwrap();
-                on_stack[w] = false;
-                component.push(w);
-                if w == v {
-                    break;
+    for start in 0..n {
+        if indices[start] != usize::MAX {
+            continue;
+        }
+
+        work.push(TarjanFrame {
+            node: start,
+            succ_idx: 0,
+        });
+
+        while let Some(frame) = work.last_mut() {
+            let v = frame.node;
+
+            if frame.succ_idx == 0 {
+                // First time visiting v: assign its index/lowlink and push
+                // it onto the SCC stack, same as the recursive entry point.
+                indices[v] = index;
+                lowlink[v] = index;
+                index += 1;
+                scc_stack.push(v);
+                on_stack[v] = true;
+            }
+
+            let successors = graph.successors_slice(v);
+            if frame.succ_idx < successors.len() {
+                let w = successors[frame.succ_idx];
+                frame.succ_idx += 1;
+
+                if indices[w] == usize::MAX {
+                    // Not visited: recurse by pushing a new frame. When it
+                    // returns we'll fold lowlink[w] into lowlink[v] below.
+                    work.push(TarjanFrame {
+                        node: w,
+                        succ_idx: 0,
+                    });
+                } else if on_stack[w] {
+                    // On stack = in current SCC
+                    lowlink[v] = lowlink[v].min(indices[w]);
                 }
+                continue;
             }
-            components.push(component);
-        }
-    }
 
-    for v in 0..n {
-        if indices[v] == usize::MAX {
-            strongconnect(
-                v,
-                graph,
-                &mut index,
-                &mut indices,
-                &mut lowlink,
-                &mut on_stack,
-                &mut stack,
-                &mut components,
-            );
+            // All successors processed: fold in the child's lowlink (if the
+            // frame below us on `work` is the caller that pushed v as w).
+            work.pop();
+            if let Some(caller) = work.last() {
+                let parent = caller.node;
+                lowlink[parent] = lowlink[parent].min(lowlink[v]);
+            }
+
+            // If v is a root node, pop the SCC stack to get the component.
+            if lowlink[v] == indices[v] {
+                let mut component = Vec::new();
+                loop {
+                    let w = scc_stack.pop().unwrap();
+                    on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
         }
     }
 
@@ -111,6 +134,71 @@ pub fn has_cycles(graph: &DiGraph) -> bool {
     tarjan_scc(graph).has_cycles
 }
 
+/// Decompose the graph into strongly connected components.
+///
+/// Every directed cycle lives entirely inside one SCC, so this is the basis
+/// for scoping cycle-break analysis (see [`cycle_break_suggestions`]) to the
+/// parts of the graph that can actually contain a cycle. Thin public alias
+/// over [`tarjan_scc`].
+pub fn strongly_connected_components(graph: &DiGraph) -> SCCResult {
+    tarjan_scc(graph)
+}
+
+/// Whether an SCC can contain a cycle: either it has more than one node, or
+/// its single node has a self-loop.
+fn is_nontrivial_scc(graph: &DiGraph, component: &[usize]) -> bool {
+    component.len() > 1
+        || (component.len() == 1 && graph.successors_slice(component[0]).contains(&component[0]))
+}
+
+/// The condensation of a graph: each SCC collapsed into a single super-node.
+pub struct Condensation {
+    /// The condensation DAG; node `i` represents `components[i]`
+    pub dag: DiGraph,
+    /// SCC index for each original node
+    pub component_of: Vec<usize>,
+    /// Original node indices making up each SCC (same order as `dag`'s nodes)
+    pub components: Vec<Vec<usize>>,
+}
+
+/// Build the condensation DAG of `graph`.
+///
+/// Every strongly connected component becomes one super-node, and an edge
+/// is added between two super-nodes whenever an edge crosses between their
+/// components. The condensation is always acyclic, so the viewer can render
+/// a large tangled graph as collapsed clusters with cycle counts per
+/// component instead of the raw node-and-edge tangle.
+pub fn condensation(graph: &DiGraph) -> Condensation {
+    let scc = tarjan_scc(graph);
+    let mut component_of = vec![0usize; graph.len()];
+    for (i, component) in scc.components.iter().enumerate() {
+        for &v in component {
+            component_of[v] = i;
+        }
+    }
+
+    let mut dag = DiGraph::new();
+    for i in 0..scc.components.len() {
+        dag.add_node(&format!("scc-{}", i));
+    }
+
+    let mut seen_edges: HashSet<(usize, usize)> = HashSet::new();
+    for u in 0..graph.len() {
+        for &v in graph.successors_slice(u) {
+            let (cu, cv) = (component_of[u], component_of[v]);
+            if cu != cv && seen_edges.insert((cu, cv)) {
+                dag.add_edge(cu, cv);
+            }
+        }
+    }
+
+    Condensation {
+        dag,
+        component_of,
+        components: scc.components,
+    }
+}
+
 /// Enumerate elementary cycles using Johnson's algorithm.
 ///
 /// Reference: Donald B. Johnson, "Finding All the Elementary Circuits of a Directed Graph"
@@ -261,6 +349,256 @@ pub fn enumerate_cycles_with_info(graph: &DiGraph, max_cycles: usize) -> CycleEn
     }
 }
 
+// ============================================================================
+// Human-Readable Cycle Paths
+// ============================================================================
+
+/// A single cycle described as an ordered chain of issues.
+#[derive(Debug, Clone, Serialize)]
+pub struct CyclePath {
+    /// Node indices in cycle order
+    pub node_indices: Vec<usize>,
+    /// Node IDs in cycle order, for display (e.g. "issue-A -> issue-B -> issue-A")
+    pub node_ids: Vec<String>,
+    /// Index into the SCC decomposition this cycle belongs to
+    pub scc_id: usize,
+}
+
+/// Describe cycles as readable node-ID chains, scoped to each SCC.
+///
+/// Runs [`tarjan_scc`] first, then for each non-trivial component runs
+/// Johnson's circuit search restricted to the subgraph induced by that
+/// component's nodes (skipping any successor outside the component). This
+/// is both faster than enumerating across the whole graph and gives the UI
+/// cycles that read as "issue-A -> issue-B -> issue-A" without edges that
+/// merely pass through an acyclic part of the graph.
+///
+/// # Arguments
+/// * `graph` - The directed graph
+/// * `max_per_scc` - Maximum cycles to report for any single SCC
+pub fn describe_cycles(graph: &DiGraph, max_per_scc: usize) -> Vec<CyclePath> {
+    let scc = tarjan_scc(graph);
+    let mut paths = Vec::new();
+
+    for (scc_id, component) in scc.components.iter().enumerate() {
+        let members: HashSet<usize> = component.iter().copied().collect();
+
+        let cycles = if component.len() > 1 {
+            enumerate_cycles_in_scc(graph, &members, max_per_scc)
+        } else {
+            // A singleton component only has a cycle if it has a self-loop.
+            let v = component[0];
+            if graph.successors_slice(v).contains(&v) {
+                vec![vec![v]]
+            } else {
+                Vec::new()
+            }
+        };
+
+        for cycle in cycles {
+            let node_ids = cycle
+                .iter()
+                .map(|&i| graph.node_id(i).unwrap_or_else(|| i.to_string()))
+                .collect();
+            paths.push(CyclePath {
+                node_indices: cycle,
+                node_ids,
+                scc_id,
+            });
+        }
+    }
+
+    paths
+}
+
+/// Unblock a node and recursively unblock its dependents (Johnson's algorithm).
+fn unblock_scoped(u: usize, blocked: &mut [bool], blocked_map: &mut [HashSet<usize>]) {
+    blocked[u] = false;
+    let dependents: Vec<usize> = blocked_map[u].drain().collect();
+    for w in dependents {
+        if blocked[w] {
+            unblock_scoped(w, blocked, blocked_map);
+        }
+    }
+}
+
+/// Johnson's circuit search restricted to `members`: successors outside the
+/// component are skipped, on top of the usual `min_node` cutoff that keeps
+/// each cycle from being reported once per starting node.
+#[allow(clippy::too_many_arguments)]
+fn circuit_scoped(
+    v: usize,
+    start: usize,
+    graph: &DiGraph,
+    members: &HashSet<usize>,
+    blocked: &mut [bool],
+    blocked_map: &mut [HashSet<usize>],
+    stack: &mut Vec<usize>,
+    cycles: &mut Vec<Vec<usize>>,
+    max_cycles: usize,
+    min_node: usize,
+) -> bool {
+    if cycles.len() >= max_cycles {
+        return false;
+    }
+
+    let mut found = false;
+    stack.push(v);
+    blocked[v] = true;
+
+    for &w in graph.successors_slice(v) {
+        if w < min_node || !members.contains(&w) {
+            continue;
+        }
+
+        if w == start {
+            cycles.push(stack.clone());
+            found = true;
+            if cycles.len() >= max_cycles {
+                stack.pop();
+                return found;
+            }
+        } else if !blocked[w]
+            && circuit_scoped(
+                w, start, graph, members, blocked, blocked_map, stack, cycles, max_cycles,
+                min_node,
+            )
+        {
+            found = true;
+        }
+    }
+
+    if found {
+        unblock_scoped(v, blocked, blocked_map);
+    } else {
+        for &w in graph.successors_slice(v) {
+            if w >= min_node && members.contains(&w) {
+                blocked_map[w].insert(v);
+            }
+        }
+    }
+
+    stack.pop();
+    found
+}
+
+/// Enumerate cycles within a single SCC, up to `max_cycles`.
+fn enumerate_cycles_in_scc(
+    graph: &DiGraph,
+    members: &HashSet<usize>,
+    max_cycles: usize,
+) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+    let mut blocked = vec![false; n];
+    let mut blocked_map: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+    let mut stack: Vec<usize> = Vec::new();
+
+    let mut sorted_members: Vec<usize> = members.iter().copied().collect();
+    sorted_members.sort_unstable();
+
+    for &start in &sorted_members {
+        if cycles.len() >= max_cycles {
+            break;
+        }
+
+        for &m in &sorted_members {
+            blocked[m] = false;
+            blocked_map[m].clear();
+        }
+
+        circuit_scoped(
+            start,
+            start,
+            graph,
+            members,
+            &mut blocked,
+            &mut blocked_map,
+            &mut stack,
+            &mut cycles,
+            max_cycles,
+            start,
+        );
+    }
+
+    cycles
+}
+
+// ============================================================================
+// Edge Provenance
+// ============================================================================
+
+/// Where a dependency edge came from, so a cycle report can explain it
+/// instead of showing a bare node list.
+///
+/// `DiGraph` itself doesn't carry this metadata on its edges, so it's
+/// supplied by the caller as a side-table keyed by edge endpoints — the
+/// viewer builds this map from whatever issue field produced each
+/// dependency (a `blocks` link, a `parent-of` relation, etc.) when it
+/// constructs the graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct EdgeProvenance {
+    /// Where in source the dependency was declared (file/line, issue field, etc.)
+    pub source_location: Option<String>,
+    /// The issue that introduced this dependency edge
+    pub issue_id: Option<String>,
+    /// The kind of dependency (e.g. "blocks", "parent-of")
+    pub dependency_kind: Option<String>,
+}
+
+/// One edge of a cycle, with its provenance if known.
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleEdge {
+    /// Source node of the edge
+    pub from: usize,
+    /// Target node of the edge
+    pub to: usize,
+    /// Provenance of this edge, if the caller supplied one
+    pub provenance: Option<EdgeProvenance>,
+}
+
+/// A cycle described as its full ordered edge path, with provenance.
+#[derive(Debug, Clone, Serialize)]
+pub struct CyclePathWithProvenance {
+    /// Edges making up the cycle, in order
+    pub edges: Vec<CycleEdge>,
+    /// Index into the SCC decomposition this cycle belongs to
+    pub scc_id: usize,
+}
+
+/// Like [`describe_cycles`], but attaches caller-supplied provenance to
+/// each edge in the cycle path, so the viewer can render e.g.
+/// "cycle: A -(blocks, issue #12)-> B -(parent-of, issue #30)-> A" instead
+/// of a bare node list.
+pub fn describe_cycles_with_provenance(
+    graph: &DiGraph,
+    provenance: &std::collections::HashMap<(usize, usize), EdgeProvenance>,
+    max_per_scc: usize,
+) -> Vec<CyclePathWithProvenance> {
+    describe_cycles(graph, max_per_scc)
+        .into_iter()
+        .map(|path| {
+            let indices = &path.node_indices;
+            let len = indices.len();
+            let edges = (0..len)
+                .map(|i| {
+                    let from = indices[i];
+                    let to = indices[(i + 1) % len];
+                    CycleEdge {
+                        from,
+                        to,
+                        provenance: provenance.get(&(from, to)).cloned(),
+                    }
+                })
+                .collect();
+            CyclePathWithProvenance {
+                edges,
+                scc_id: path.scc_id,
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // Cycle Break Suggestions
 // ============================================================================
@@ -280,6 +618,9 @@ pub struct CycleBreakItem {
     pub from_id: Option<String>,
     /// Node ID for target
     pub to_id: Option<String>,
+    /// Provenance of this edge, if the caller supplied a provenance map via
+    /// [`cycle_break_suggestions_with_provenance`]
+    pub provenance: Option<EdgeProvenance>,
 }
 
 /// Result of cycle break analysis.
@@ -291,6 +632,11 @@ pub struct CycleBreakResult {
     pub total_cycles: usize,
     /// Whether cycle enumeration was truncated
     pub truncated: bool,
+    /// Full ordered edge path (with provenance) of each cycle found by
+    /// [`describe_cycles_with_provenance`], up to `max_cycles_to_enumerate`
+    /// per SCC, so its length can differ from `total_cycles`. Populated by
+    /// [`cycle_break_suggestions_with_provenance`]; empty otherwise.
+    pub cycle_paths: Vec<CyclePathWithProvenance>,
 }
 
 /// Analyze cycles and suggest edges to remove to break them.
@@ -302,6 +648,10 @@ pub struct CycleBreakResult {
 /// Suggestions are sorted by: cycles_broken desc, then collateral asc
 /// (prefer edges that break many cycles with minimal disruption)
 ///
+/// Carries no edge provenance or full cycle paths of its own — use
+/// [`cycle_break_suggestions_with_provenance`] for that, since `DiGraph`
+/// doesn't store provenance on edges (see [`EdgeProvenance`]).
+///
 /// # Arguments
 /// * `graph` - The directed graph
 /// * `limit` - Maximum suggestions to return
@@ -312,11 +662,23 @@ pub fn cycle_break_suggestions(
     max_cycles_to_enumerate: usize,
 ) -> CycleBreakResult {
     let scc = tarjan_scc(graph);
-    if !scc.has_cycles {
+
+    // Build set of nodes in non-trivial SCCs: size > 1, or a self-loop
+    // (every directed cycle, including a 1-node self-loop, lives entirely
+    // inside one SCC).
+    let cycle_nodes: HashSet<usize> = scc
+        .components
+        .iter()
+        .filter(|c| is_nontrivial_scc(graph, c))
+        .flat_map(|c| c.iter().copied())
+        .collect();
+
+    if cycle_nodes.is_empty() {
         return CycleBreakResult {
             suggestions: Vec::new(),
             total_cycles: 0,
             truncated: false,
+            cycle_paths: Vec::new(),
         };
     }
 
@@ -329,10 +691,10 @@ pub fn cycle_break_suggestions(
         std::collections::HashMap::new();
 
     for cycle in cycles {
-        if cycle.len() < 2 {
+        if cycle.is_empty() {
             continue;
         }
-        // Count edges in this cycle
+        // Count edges in this cycle (a single-node cycle is a self-loop)
         for i in 0..cycle.len() {
             let from = cycle[i];
             let to = cycle[(i + 1) % cycle.len()];
@@ -340,14 +702,6 @@ pub fn cycle_break_suggestions(
         }
     }
 
-    // Build set of nodes in non-trivial SCCs
-    let cycle_nodes: HashSet<usize> = scc
-        .components
-        .iter()
-        .filter(|c| c.len() > 1)
-        .flat_map(|c| c.iter().copied())
-        .collect();
-
     // Find all edges within cycle SCCs
     let mut suggestions: Vec<CycleBreakItem> = Vec::new();
 
@@ -364,6 +718,7 @@ pub fn cycle_break_suggestions(
                     collateral,
                     from_id: graph.node_id(from),
                     to_id: graph.node_id(to),
+                    provenance: None,
                 });
             }
         }
@@ -383,7 +738,27 @@ pub fn cycle_break_suggestions(
         suggestions,
         total_cycles: cycle_info.count,
         truncated: cycle_info.truncated,
+        cycle_paths: Vec::new(),
+    }
+}
+
+/// Like [`cycle_break_suggestions`], but attaches caller-supplied edge
+/// provenance to both the suggested edges and the full ordered cycle paths
+/// that justify them, so the viewer can render e.g. "remove A
+/// -(blocks, issue #12)-> B to break 3 cycles" instead of a bare
+/// node-index suggestion.
+pub fn cycle_break_suggestions_with_provenance(
+    graph: &DiGraph,
+    provenance: &std::collections::HashMap<(usize, usize), EdgeProvenance>,
+    limit: usize,
+    max_cycles_to_enumerate: usize,
+) -> CycleBreakResult {
+    let mut result = cycle_break_suggestions(graph, limit, max_cycles_to_enumerate);
+    for item in &mut result.suggestions {
+        item.provenance = provenance.get(&(item.from, item.to)).cloned();
     }
+    result.cycle_paths = describe_cycles_with_provenance(graph, provenance, max_cycles_to_enumerate);
+    result
 }
 
 /// Quick check for edges that could break cycles.
@@ -392,18 +767,19 @@ pub fn cycle_break_suggestions(
 /// full cycle enumeration. Faster but less precise scoring.
 pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakItem> {
     let scc = tarjan_scc(graph);
-    if !scc.has_cycles {
-        return Vec::new();
-    }
 
-    // Build set of nodes in non-trivial SCCs
+    // Build set of nodes in non-trivial SCCs: size > 1, or a self-loop.
     let cycle_nodes: HashSet<usize> = scc
         .components
         .iter()
-        .filter(|c| c.len() > 1)
+        .filter(|c| is_nontrivial_scc(graph, c))
         .flat_map(|c| c.iter().copied())
         .collect();
 
+    if cycle_nodes.is_empty() {
+        return Vec::new();
+    }
+
     let mut suggestions: Vec<CycleBreakItem> = Vec::new();
 
     for &from in &cycle_nodes {
@@ -419,6 +795,7 @@ pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakI
                     collateral,
                     from_id: graph.node_id(from),
                     to_id: graph.node_id(to),
+                    provenance: None,
                 });
             }
         }
@@ -430,91 +807,496 @@ pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakI
     suggestions
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// ============================================================================
+// Minimum Feedback Arc Set (Eades-Lin-Smyth heuristic)
+// ============================================================================
 
-    #[test]
-    fn test_scc_empty() {
-        let graph = DiGraph::new();
-        let result = tarjan_scc(&graph);
-        assert!(result.components.is_empty());
-        assert!(!result.has_cycles);
-    }
+/// A directed edge identified by its endpoint node indices.
+pub type EdgeId = (usize, usize);
 
-    #[test]
-    fn test_scc_single_node() {
-        let mut graph = DiGraph::new();
-        graph.add_node("a");
-        let result = tarjan_scc(&graph);
-        assert_eq!(result.components.len(), 1);
-        assert_eq!(result.components[0].len(), 1);
-        assert!(!result.has_cycles);
+/// Edge set whose removal is guaranteed to make the graph acyclic.
+///
+/// Unlike [`cycle_break_suggestions`], which scores edges per-cycle and
+/// offers no guarantee that applying its suggestions together breaks every
+/// cycle, this builds a single linear vertex order using the GR heuristic
+/// (Eades, Lin, Smyth 1993) and returns every edge that points backward in
+/// that order — exactly the edges whose removal leaves a DAG.
+///
+/// The order is built by repeatedly (a) appending every current sink to a
+/// tail sequence and removing it, (b) moving every current
+/// source to the back of a head sequence and removing it, then (c), while
+/// vertices remain, picking the vertex maximizing `out_degree - in_degree`,
+/// appending it to the head sequence, and removing it. Concatenating
+/// `head ++ reversed(tail)` gives the final order. Runs in O(V + E) using
+/// degree buckets, and on graphs with no 2-cycles or self-loops the
+/// resulting arc set is guaranteed to satisfy `|FAS| <= m/2 - n/6`
+/// (Eades, Lin, Smyth 1993); a graph with 2-cycles can exceed that bound
+/// (`a <-> b` alone needs one arc). This gives a near-minimal,
+/// single coherent "make acyclic" answer instead of a per-cycle suggestion
+/// list that has to be re-applied one edge at a time.
+pub fn feedback_arc_set(graph: &DiGraph) -> Vec<EdgeId> {
+    feedback_arc_set_with_order(graph).0
+}
+
+/// Like [`feedback_arc_set`], but also returns the linear vertex order the
+/// GR heuristic used to pick those arcs. Every edge not in the arc set
+/// points forward in this order, so it doubles as a topological order of
+/// the DAG left behind once the arc set is removed — useful when a caller
+/// wants to both break the cycles and immediately lay out the result
+/// without re-deriving the order from scratch.
+pub fn feedback_arc_set_with_order(graph: &DiGraph) -> (Vec<EdgeId>, Vec<usize>) {
+    let order = gr_vertex_order(graph);
+    let mut position = vec![0usize; order.len()];
+    for (pos, &v) in order.iter().enumerate() {
+        position[v] = pos;
     }
 
-    #[test]
-    fn test_scc_self_loop() {
-        let mut graph = DiGraph::new();
-        let a = graph.add_node("a");
-        graph.add_edge(a, a);
-        let result = tarjan_scc(&graph);
-        // Self-loop creates SCC of size 1 with edge to itself
-        // Tarjan considers this a cycle
-        assert!(result.has_cycles || result.components[0].len() == 1);
+    let mut arcs = Vec::new();
+    for u in 0..graph.len() {
+        for &v in graph.successors_slice(u) {
+            // A self-loop is always a 1-node cycle; every other edge is a
+            // feedback arc exactly when it points backward in the order.
+            if u == v || position[u] > position[v] {
+                arcs.push((u, v));
+            }
+        }
     }
+    (arcs, order)
+}
 
-    #[test]
-    fn test_scc_simple_cycle() {
-        // a -> b -> c -> a
-        let mut graph = DiGraph::new();
-        let a = graph.add_node("a");
-        let b = graph.add_node("b");
-        let c = graph.add_node("c");
-        graph.add_edge(a, b);
-        graph.add_edge(b, c);
-        graph.add_edge(c, a);
+/// Requeue `w` into the sink/source/delta-bucket structure after one of its
+/// neighbors was removed and its degree changed.
+///
+/// Removing a max-bucket vertex can *raise* one of its remaining
+/// successors' `out_deg - in_deg` (its in-degree just dropped), so a fresh
+/// push here can land above the current `max_bucket` pointer, not just
+/// below it — bump `max_bucket` up to match whenever that happens, or the
+/// scan-down in [`gr_vertex_order`] would never look at it again.
+fn gr_touch(
+    w: usize,
+    removed: &[bool],
+    out_deg: &[i64],
+    in_deg: &[i64],
+    sinks: &mut Vec<usize>,
+    sources: &mut Vec<usize>,
+    buckets: &mut [Vec<usize>],
+    offset: i64,
+    max_bucket: &mut usize,
+) {
+    if removed[w] {
+        return;
+    }
+    if out_deg[w] == 0 {
+        sinks.push(w);
+    } else if in_deg[w] == 0 {
+        sources.push(w);
+    } else {
+        let bucket = (out_deg[w] - in_deg[w] + offset) as usize;
+        buckets[bucket].push(w);
+        if bucket > *max_bucket {
+            *max_bucket = bucket;
+        }
+    }
+}
 
-        let result = tarjan_scc(&graph);
-        assert!(result.has_cycles);
-        assert_eq!(result.cycle_count, 1);
-        // One SCC with all 3 nodes
-        let big_scc = result.components.iter().find(|c| c.len() > 1);
-        assert!(big_scc.is_some());
-        assert_eq!(big_scc.unwrap().len(), 3);
+/// Compute the GR linear vertex order used by [`feedback_arc_set`].
+fn gr_vertex_order(graph: &DiGraph) -> Vec<usize> {
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
     }
 
-    #[test]
-    fn test_scc_dag() {
-        // a -> b -> c (no cycles)
-        let mut graph = DiGraph::new();
-        let a = graph.add_node("a");
-        let b = graph.add_node("b");
-        let c = graph.add_node("c");
-        graph.add_edge(a, b);
-        graph.add_edge(b, c);
+    let mut out_deg: Vec<i64> = (0..n).map(|v| graph.successors_slice(v).len() as i64).collect();
+    let mut in_deg: Vec<i64> = (0..n).map(|v| graph.predecessors_slice(v).len() as i64).collect();
+    let mut removed = vec![false; n];
 
-        let result = tarjan_scc(&graph);
-        assert!(!result.has_cycles);
-        // Each node is its own SCC
-        assert_eq!(result.components.len(), 3);
+    // Bucket vertices by out_deg - in_deg, offset to a non-negative index.
+    let offset = (n - 1) as i64;
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); 2 * n - 1];
+    for v in 0..n {
+        buckets[(out_deg[v] - in_deg[v] + offset) as usize].push(v);
     }
+    let mut max_bucket = buckets.len() - 1;
 
-    #[test]
-    fn test_scc_two_cycles() {
-        // Cycle 1: a -> b -> a
-        // Cycle 2: c -> d -> c
-        let mut graph = DiGraph::new();
-        let a = graph.add_node("a");
-        let b = graph.add_node("b");
-        let c = graph.add_node("c");
-        let d = graph.add_node("d");
-        graph.add_edge(a, b);
-        graph.add_edge(b, a);
-        graph.add_edge(c, d);
-        graph.add_edge(d, c);
+    let mut sinks: Vec<usize> = (0..n).filter(|&v| out_deg[v] == 0).collect();
+    let mut sources: Vec<usize> = (0..n).filter(|&v| in_deg[v] == 0).collect();
 
-        let result = tarjan_scc(&graph);
-        assert!(result.has_cycles);
+    let mut head: Vec<usize> = Vec::with_capacity(n);
+    let mut tail: Vec<usize> = Vec::with_capacity(n);
+    let mut remaining = n;
+
+    while remaining > 0 {
+        // Peel all current sinks onto the tail.
+        while let Some(v) = sinks.pop() {
+            if removed[v] || out_deg[v] != 0 {
+                continue;
+            }
+            removed[v] = true;
+            remaining -= 1;
+            tail.push(v);
+            for &u in graph.predecessors_slice(v) {
+                if !removed[u] {
+                    out_deg[u] -= 1;
+                    gr_touch(u, &removed, &out_deg, &in_deg, &mut sinks, &mut sources, &mut buckets, offset, &mut max_bucket);
+                }
+            }
+        }
+        // Peel all current sources onto the head.
+        while let Some(v) = sources.pop() {
+            if removed[v] || in_deg[v] != 0 {
+                continue;
+            }
+            removed[v] = true;
+            remaining -= 1;
+            head.push(v);
+            for &w in graph.successors_slice(v) {
+                if !removed[w] {
+                    in_deg[w] -= 1;
+                    gr_touch(w, &removed, &out_deg, &in_deg, &mut sinks, &mut sources, &mut buckets, offset, &mut max_bucket);
+                }
+            }
+        }
+        if remaining == 0 {
+            break;
+        }
+
+        // Otherwise take the remaining vertex maximizing out_deg - in_deg.
+        // `gr_touch` re-pushes a vertex into a new bucket whenever its degree
+        // changes but never removes the old entry, so a popped entry can be
+        // stale (its current bucket no longer matches the one we popped from)
+        // — discard those without reinserting, the same way sinks/sources are
+        // re-validated above.
+        let v = loop {
+            while max_bucket > 0 && buckets[max_bucket].is_empty() {
+                max_bucket -= 1;
+            }
+            match buckets[max_bucket].pop() {
+                Some(v)
+                    if !removed[v]
+                        && out_deg[v] != 0
+                        && in_deg[v] != 0
+                        && (out_deg[v] - in_deg[v] + offset) as usize == max_bucket =>
+                {
+                    break Some(v)
+                }
+                Some(_) => continue,
+                None if max_bucket == 0 => break None,
+                None => continue,
+            }
+        };
+        let Some(v) = v else { break };
+
+        removed[v] = true;
+        remaining -= 1;
+        head.push(v);
+        for &w in graph.successors_slice(v) {
+            if !removed[w] {
+                in_deg[w] -= 1;
+                gr_touch(w, &removed, &out_deg, &in_deg, &mut sinks, &mut sources, &mut buckets, offset, &mut max_bucket);
+            }
+        }
+        for &u in graph.predecessors_slice(v) {
+            if !removed[u] {
+                out_deg[u] -= 1;
+                gr_touch(u, &removed, &out_deg, &in_deg, &mut sinks, &mut sources, &mut buckets, offset, &mut max_bucket);
+            }
+        }
+    }
+
+    tail.reverse();
+    head.extend(tail);
+    head
+}
+
+// ============================================================================
+// Back-Edge Classification and Decycling by Reversal
+// ============================================================================
+
+/// One frame of the explicit DFS work-stack used by [`back_edges`].
+struct DfsFrame {
+    node: usize,
+    succ_idx: usize,
+}
+
+/// Find every back edge in a DFS forest of the graph.
+///
+/// Colors nodes white (unvisited), gray (on the active DFS path), or black
+/// (finished) and reports every edge `(u, v)` where `v` is gray when `u` is
+/// explored — these are exactly the edges whose reversal makes the graph
+/// acyclic, giving a lossless alternative to deleting a dependency outright.
+/// A self-loop `(v, v)` is reported too, but reversing it leaves it in place.
+pub fn back_edges(graph: &DiGraph) -> Vec<(usize, usize)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Color {
+        White,
+        Gray,
+        Black,
+    }
+
+    let n = graph.len();
+    let mut color = vec![Color::White; n];
+    let mut edges = Vec::new();
+    let mut work: Vec<DfsFrame> = Vec::new();
+
+    for start in 0..n {
+        if color[start] != Color::White {
+            continue;
+        }
+        color[start] = Color::Gray;
+        work.push(DfsFrame {
+            node: start,
+            succ_idx: 0,
+        });
+
+        while let Some(frame) = work.last_mut() {
+            let v = frame.node;
+            let successors = graph.successors_slice(v);
+            if frame.succ_idx < successors.len() {
+                let w = successors[frame.succ_idx];
+                frame.succ_idx += 1;
+                match color[w] {
+                    Color::White => {
+                        color[w] = Color::Gray;
+                        work.push(DfsFrame {
+                            node: w,
+                            succ_idx: 0,
+                        });
+                    }
+                    Color::Gray => edges.push((v, w)),
+                    Color::Black => {}
+                }
+            } else {
+                color[v] = Color::Black;
+                work.pop();
+            }
+        }
+    }
+
+    edges
+}
+
+/// Clone the graph with every back edge reversed, producing a DAG when the
+/// graph has no self-loops (a self-loop is copied unchanged, since
+/// reversing it yields the same edge).
+///
+/// A lossless alternative to [`cycle_break_suggestions`]: instead of
+/// deleting a dependency to break a cycle, flip its direction so no work is
+/// lost and the graph becomes rankable.
+pub fn decycle_by_reversal(graph: &DiGraph) -> DiGraph {
+    let reversed: HashSet<(usize, usize)> = back_edges(graph).into_iter().collect();
+
+    let mut result = DiGraph::new();
+    for v in 0..graph.len() {
+        let id = graph.node_id(v).unwrap_or_else(|| v.to_string());
+        result.add_node(&id);
+    }
+    for u in 0..graph.len() {
+        for &v in graph.successors_slice(u) {
+            if reversed.contains(&(u, v)) {
+                result.add_edge(v, u);
+            } else {
+                result.add_edge(u, v);
+            }
+        }
+    }
+
+    result
+}
+
+// ============================================================================
+// Fundamental Cycle Basis
+// ============================================================================
+
+/// Walk `u` and `v` up their spanning-forest parent pointers to build the
+/// fundamental cycle closed by the non-tree edge `(u, v)`.
+fn fundamental_cycle(u: usize, v: usize, parent: &[usize], depth: &[usize]) -> Vec<usize> {
+    let mut path_u = vec![u];
+    let mut path_v = vec![v];
+    let mut cu = u;
+    let mut cv = v;
+
+    while depth[cu] > depth[cv] {
+        cu = parent[cu];
+        path_u.push(cu);
+    }
+    while depth[cv] > depth[cu] {
+        cv = parent[cv];
+        path_v.push(cv);
+    }
+    while cu != cv {
+        cu = parent[cu];
+        path_u.push(cu);
+        cv = parent[cv];
+        path_v.push(cv);
+    }
+    // path_u and path_v both now end at the lowest common ancestor.
+
+    path_v.pop(); // drop the duplicated LCA
+    path_v.reverse();
+    path_u.extend(path_v);
+    path_u
+}
+
+/// Compute a fundamental cycle basis of the graph.
+///
+/// Builds a spanning forest (treating edges as undirected) via DFS,
+/// recording each node's parent. Every non-tree edge `(u, v)` then induces
+/// exactly one fundamental cycle, formed by walking `u` and `v` up to their
+/// lowest common ancestor through parent pointers and closing with `(u, v)`.
+///
+/// The number of basis cycles is exactly `|E| - |V| + components`, so this
+/// is linear in graph size and gives a minimal, non-redundant set of cycles
+/// that still spans the entire cycle space — far more stable than truncated
+/// Johnson enumeration on dense graphs.
+pub fn cycle_basis(graph: &DiGraph) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut parent = vec![usize::MAX; n];
+    let mut depth = vec![0usize; n];
+    let mut visited = vec![false; n];
+    let mut tree_edges: HashSet<(usize, usize)> = HashSet::new();
+
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        parent[start] = start;
+        let mut stack = vec![start];
+
+        while let Some(v) = stack.pop() {
+            // Record the exact directed arc used to reach each new node, so
+            // a reciprocal (u, v) + (v, u) pair still yields a 2-cycle
+            // instead of being treated as a single undirected tree edge.
+            for &w in graph.successors_slice(v) {
+                if w == v || visited[w] {
+                    continue;
+                }
+                visited[w] = true;
+                parent[w] = v;
+                depth[w] = depth[v] + 1;
+                tree_edges.insert((v, w));
+                stack.push(w);
+            }
+            for &w in graph.predecessors_slice(v) {
+                if w == v || visited[w] {
+                    continue;
+                }
+                visited[w] = true;
+                parent[w] = v;
+                depth[w] = depth[v] + 1;
+                tree_edges.insert((w, v));
+                stack.push(w);
+            }
+        }
+    }
+
+    let mut basis = Vec::new();
+    for u in 0..n {
+        for &v in graph.successors_slice(u) {
+            if u == v {
+                // A self-loop is its own 1-node fundamental cycle.
+                basis.push(vec![u]);
+                continue;
+            }
+            if tree_edges.contains(&(u, v)) {
+                continue;
+            }
+            basis.push(fundamental_cycle(u, v, &parent, &depth));
+        }
+    }
+
+    basis
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scc_empty() {
+        let graph = DiGraph::new();
+        let result = tarjan_scc(&graph);
+        assert!(result.components.is_empty());
+        assert!(!result.has_cycles);
+    }
+
+    #[test]
+    fn test_scc_single_node() {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        let result = tarjan_scc(&graph);
+        assert_eq!(result.components.len(), 1);
+        assert_eq!(result.components[0].len(), 1);
+        assert!(!result.has_cycles);
+    }
+
+    #[test]
+    fn test_scc_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        graph.add_edge(a, a);
+        let result = tarjan_scc(&graph);
+        // Self-loop creates SCC of size 1 with edge to itself
+        // Tarjan considers this a cycle
+        assert!(result.has_cycles || result.components[0].len() == 1);
+    }
+
+    #[test]
+    fn test_scc_simple_cycle() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let result = tarjan_scc(&graph);
+        assert!(result.has_cycles);
+        assert_eq!(result.cycle_count, 1);
+        // One SCC with all 3 nodes
+        let big_scc = result.components.iter().find(|c| c.len() > 1);
+        assert!(big_scc.is_some());
+        assert_eq!(big_scc.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_scc_dag() {
+        // a -> b -> c (no cycles)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let result = tarjan_scc(&graph);
+        assert!(!result.has_cycles);
+        // Each node is its own SCC
+        assert_eq!(result.components.len(), 3);
+    }
+
+    #[test]
+    fn test_scc_two_cycles() {
+        // Cycle 1: a -> b -> a
+        // Cycle 2: c -> d -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(c, d);
+        graph.add_edge(d, c);
+
+        let result = tarjan_scc(&graph);
+        assert!(result.has_cycles);
         assert_eq!(result.cycle_count, 2);
     }
 
@@ -624,32 +1406,307 @@ mod tests {
         graph.add_edge(a, b);
         graph.add_edge(b, a);
 
-        let result = enumerate_cycles_with_info(&graph, 100);
-        assert_eq!(result.count, 1);
-        assert!(!result.truncated);
+        let result = enumerate_cycles_with_info(&graph, 100);
+        assert_eq!(result.count, 1);
+        assert!(!result.truncated);
+
+        // With limit of 1, we should get exactly 1 cycle and not be truncated
+        // (since there's only 1 cycle to find)
+        let result_one = enumerate_cycles_with_info(&graph, 1);
+        assert_eq!(result_one.count, 1);
+        // Truncated because we hit the limit (count >= max)
+        assert!(result_one.truncated);
+    }
+
+    // ========================================================================
+    // Describe Cycles Tests
+    // ========================================================================
+
+    #[test]
+    fn test_describe_cycles_dag_is_empty() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("issue-a");
+        let b = graph.add_node("issue-b");
+        graph.add_edge(a, b);
+
+        assert!(describe_cycles(&graph, 10).is_empty());
+    }
+
+    #[test]
+    fn test_describe_cycles_simple() {
+        // issue-a -> issue-b -> issue-a
+        let mut graph = DiGraph::new();
+        graph.add_node("issue-a");
+        graph.add_node("issue-b");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+
+        let paths = describe_cycles(&graph, 10);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].node_ids, vec!["issue-a", "issue-b"]);
+    }
+
+    #[test]
+    fn test_describe_cycles_self_loop() {
+        let mut graph = DiGraph::new();
+        graph.add_node("issue-a");
+        graph.add_edge(0, 0);
+
+        let paths = describe_cycles(&graph, 10);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].node_indices, vec![0]);
+    }
+
+    #[test]
+    fn test_describe_cycles_scoped_to_each_scc() {
+        // Two disjoint cycles: a<->b and c<->d
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        graph.add_node("b");
+        graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 2);
+
+        let paths = describe_cycles(&graph, 10);
+        assert_eq!(paths.len(), 2);
+        // Cycles come from distinct SCCs
+        let scc_ids: HashSet<usize> = paths.iter().map(|p| p.scc_id).collect();
+        assert_eq!(scc_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_describe_cycles_respects_max_per_scc() {
+        // A plain 5-node ring produces only 1 elementary cycle, so widen it
+        // with chords to get several cycles through the same SCC.
+        let mut graph = DiGraph::new();
+        for i in 0..5 {
+            graph.add_node(&format!("n{}", i));
+        }
+        for i in 0..5 {
+            graph.add_edge(i, (i + 1) % 5);
+        }
+        graph.add_edge(0, 2);
+        graph.add_edge(2, 0);
+
+        let paths = describe_cycles(&graph, 1);
+        assert_eq!(paths.len(), 1);
+    }
+
+    // ========================================================================
+    // Edge Provenance Tests
+    // ========================================================================
+
+    #[test]
+    fn test_describe_cycles_with_provenance_attaches_known_edges() {
+        // issue-a -(blocks)-> issue-b -(parent-of)-> issue-a
+        let mut graph = DiGraph::new();
+        graph.add_node("issue-a");
+        graph.add_node("issue-b");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+
+        let mut provenance = std::collections::HashMap::new();
+        provenance.insert(
+            (0, 1),
+            EdgeProvenance {
+                source_location: Some("issues.json:12".to_string()),
+                issue_id: Some("issue #12".to_string()),
+                dependency_kind: Some("blocks".to_string()),
+            },
+        );
+
+        let paths = describe_cycles_with_provenance(&graph, &provenance, 10);
+        assert_eq!(paths.len(), 1);
+        let edge_0_1 = paths[0].edges.iter().find(|e| e.from == 0 && e.to == 1).unwrap();
+        assert_eq!(
+            edge_0_1.provenance.as_ref().unwrap().dependency_kind,
+            Some("blocks".to_string())
+        );
+        let edge_1_0 = paths[0].edges.iter().find(|e| e.from == 1 && e.to == 0).unwrap();
+        assert!(edge_1_0.provenance.is_none());
+    }
+
+    #[test]
+    fn test_describe_cycles_with_provenance_empty_map() {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        graph.add_node("b");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+
+        let provenance = std::collections::HashMap::new();
+        let paths = describe_cycles_with_provenance(&graph, &provenance, 10);
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].edges.iter().all(|e| e.provenance.is_none()));
+    }
+
+    #[test]
+    fn test_cycle_break_suggestions_with_provenance_attaches_to_suggestions() {
+        // issue-a -(blocks)-> issue-b -(unknown)-> issue-a
+        let mut graph = DiGraph::new();
+        graph.add_node("issue-a");
+        graph.add_node("issue-b");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+
+        let mut provenance = std::collections::HashMap::new();
+        provenance.insert(
+            (0, 1),
+            EdgeProvenance {
+                source_location: Some("issues.json:12".to_string()),
+                issue_id: Some("issue #12".to_string()),
+                dependency_kind: Some("blocks".to_string()),
+            },
+        );
+
+        let result = cycle_break_suggestions_with_provenance(&graph, &provenance, 10, 100);
+
+        let known = result
+            .suggestions
+            .iter()
+            .find(|s| s.from == 0 && s.to == 1)
+            .unwrap();
+        assert_eq!(
+            known.provenance.as_ref().unwrap().dependency_kind,
+            Some("blocks".to_string())
+        );
+        let unknown = result
+            .suggestions
+            .iter()
+            .find(|s| s.from == 1 && s.to == 0)
+            .unwrap();
+        assert!(unknown.provenance.is_none());
+
+        // The full cycle path (with provenance) is also carried on the result.
+        assert_eq!(result.cycle_paths.len(), 1);
+    }
+
+    #[test]
+    fn test_cycle_break_suggestions_without_provenance_has_empty_paths() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let result = cycle_break_suggestions(&graph, 10, 100);
+        assert!(result.cycle_paths.is_empty());
+        assert!(result.suggestions.iter().all(|s| s.provenance.is_none()));
+    }
+
+    #[test]
+    fn test_has_cycles() {
+        let mut dag = DiGraph::new();
+        let a = dag.add_node("a");
+        let b = dag.add_node("b");
+        dag.add_edge(a, b);
+        assert!(!has_cycles(&dag));
+
+        let mut cyclic = DiGraph::new();
+        let x = cyclic.add_node("x");
+        let y = cyclic.add_node("y");
+        cyclic.add_edge(x, y);
+        cyclic.add_edge(y, x);
+        assert!(has_cycles(&cyclic));
+    }
+
+    // ========================================================================
+    // SCC Condensation Tests
+    // ========================================================================
+
+    #[test]
+    fn test_strongly_connected_components_matches_tarjan_scc() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert_eq!(
+            strongly_connected_components(&graph).cycle_count,
+            tarjan_scc(&graph).cycle_count
+        );
+    }
+
+    #[test]
+    fn test_condensation_is_acyclic() {
+        // Two cycles joined by a bridge edge: a<->b -> c<->d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(d, c);
+
+        let cond = condensation(&graph);
+        assert_eq!(cond.dag.len(), 2);
+        assert!(!has_cycles(&cond.dag));
+        assert_eq!(cond.component_of[a], cond.component_of[b]);
+        assert_eq!(cond.component_of[c], cond.component_of[d]);
+        assert_ne!(cond.component_of[a], cond.component_of[c]);
+    }
+
+    #[test]
+    fn test_condensation_dag_on_acyclic_graph() {
+        // Already a DAG: each node is its own SCC
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let cond = condensation(&graph);
+        assert_eq!(cond.dag.len(), 2);
+        assert_ne!(cond.component_of[a], cond.component_of[b]);
+    }
+
+    #[test]
+    fn test_cycle_break_suggestions_includes_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        graph.add_edge(a, a);
+
+        let result = cycle_break_suggestions(&graph, 10, 100);
+        assert_eq!(result.suggestions.len(), 1);
+        assert_eq!(result.suggestions[0].from, a);
+        assert_eq!(result.suggestions[0].to, a);
+    }
+
+    #[test]
+    fn test_quick_cycle_break_edges_includes_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        graph.add_edge(a, a);
 
-        // With limit of 1, we should get exactly 1 cycle and not be truncated
-        // (since there's only 1 cycle to find)
-        let result_one = enumerate_cycles_with_info(&graph, 1);
-        assert_eq!(result_one.count, 1);
-        // Truncated because we hit the limit (count >= max)
-        assert!(result_one.truncated);
+        let suggestions = quick_cycle_break_edges(&graph, 10);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].from, a);
+        assert_eq!(suggestions[0].to, a);
     }
 
     #[test]
-    fn test_has_cycles() {
-        let mut dag = DiGraph::new();
-        let a = dag.add_node("a");
-        let b = dag.add_node("b");
-        dag.add_edge(a, b);
-        assert!(!has_cycles(&dag));
+    fn test_tarjan_scc_deep_chain_does_not_overflow_stack() {
+        // A long linear chain is the worst case for a recursive
+        // strongconnect: the iterative work-stack must handle it in
+        // bounded heap memory instead of native stack depth.
+        let mut graph = DiGraph::new();
+        const N: usize = 50_000;
+        for i in 0..N {
+            graph.add_node(&format!("n{}", i));
+        }
+        for i in 0..N - 1 {
+            graph.add_edge(i, i + 1);
+        }
 
-        let mut cyclic = DiGraph::new();
-        let x = cyclic.add_node("x");
-        let y = cyclic.add_node("y");
-        cyclic.add_edge(x, y);
-        cyclic.add_edge(y, x);
-        assert!(has_cycles(&cyclic));
+        let result = tarjan_scc(&graph);
+        assert!(!result.has_cycles);
+        assert_eq!(result.components.len(), N);
     }
 
     #[test]
@@ -875,4 +1932,379 @@ mod tests {
             }
         }
     }
+
+    // ========================================================================
+    // Feedback Arc Set Tests
+    // ========================================================================
+
+    /// Check that removing `arcs` from `graph` leaves no cycle.
+    fn is_acyclic_after_removal(graph: &DiGraph, arcs: &[(usize, usize)]) -> bool {
+        let removed: HashSet<(usize, usize)> = arcs.iter().copied().collect();
+        let n = graph.len();
+        let mut in_degree = vec![0usize; n];
+        for u in 0..n {
+            for &v in graph.successors_slice(u) {
+                if !removed.contains(&(u, v)) {
+                    in_degree[v] += 1;
+                }
+            }
+        }
+        let mut queue: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+        let mut visited = 0;
+        let mut head = 0;
+        while head < queue.len() {
+            let v = queue[head];
+            head += 1;
+            visited += 1;
+            for &w in graph.successors_slice(v) {
+                if !removed.contains(&(v, w)) {
+                    in_degree[w] -= 1;
+                    if in_degree[w] == 0 {
+                        queue.push(w);
+                    }
+                }
+            }
+        }
+        visited == n
+    }
+
+    #[test]
+    fn test_feedback_arc_set_dag_is_empty() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        assert!(feedback_arc_set(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_feedback_arc_set_breaks_simple_cycle() {
+        // a -> b -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let fas = feedback_arc_set(&graph);
+        assert_eq!(fas.len(), 1);
+        assert!(is_acyclic_after_removal(&graph, &fas));
+    }
+
+    #[test]
+    fn test_feedback_arc_set_breaks_triangle() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let fas = feedback_arc_set(&graph);
+        assert!(is_acyclic_after_removal(&graph, &fas));
+        assert!(!fas.is_empty());
+    }
+
+    #[test]
+    fn test_feedback_arc_set_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        graph.add_edge(a, a);
+
+        let fas = feedback_arc_set(&graph);
+        assert_eq!(fas, vec![(a, a)]);
+    }
+
+    #[test]
+    fn test_feedback_arc_set_complex_graph() {
+        // Several interlocking cycles
+        let mut graph = DiGraph::new();
+        for i in 0..6 {
+            graph.add_node(&format!("n{}", i));
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 2);
+        graph.add_edge(4, 5);
+        graph.add_edge(5, 3);
+
+        let fas = feedback_arc_set(&graph);
+        assert!(is_acyclic_after_removal(&graph, &fas));
+    }
+
+    #[test]
+    fn test_feedback_arc_set_respects_eades_lin_smyth_bound() {
+        // The |FAS| <= m/2 - n/6 guarantee (Eades, Lin, Smyth 1993) is
+        // stated for simple digraphs with no 2-cycles (at most one edge per
+        // unordered pair). A tournament — every pair connected by exactly
+        // one directed edge — is the natural stress case: it's densely
+        // cyclic without violating that assumption. (A graph with both
+        // `(i, j)` and `(j, i)` for every pair forces |FAS| = m/2 under any
+        // vertex order, which exceeds this bound for every n and isn't a
+        // valid input for the theorem.)
+        let mut graph = DiGraph::new();
+        const N: usize = 8;
+        for i in 0..N {
+            graph.add_node(&format!("n{}", i));
+        }
+        let mut m: usize = 0;
+        for i in 0..N {
+            for j in (i + 1)..N {
+                // Orient each pair once, wrapping around so the result is
+                // densely cyclic rather than a transitive tournament.
+                if (j - i) <= N / 2 {
+                    graph.add_edge(i, j);
+                } else {
+                    graph.add_edge(j, i);
+                }
+                m += 1;
+            }
+        }
+
+        let fas = feedback_arc_set(&graph);
+        assert!(is_acyclic_after_removal(&graph, &fas));
+        let bound = (m as f64) / 2.0 - (N as f64) / 6.0;
+        assert!(
+            (fas.len() as f64) <= bound,
+            "|FAS| = {} exceeds bound {}",
+            fas.len(),
+            bound
+        );
+    }
+
+    #[test]
+    fn test_feedback_arc_set_empty_graph() {
+        let graph = DiGraph::new();
+        assert!(feedback_arc_set(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_feedback_arc_set_with_order_matches_plain_arcs() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let (arcs, order) = feedback_arc_set_with_order(&graph);
+        assert_eq!(arcs, feedback_arc_set(&graph));
+        assert_eq!(order.len(), 3);
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_feedback_arc_set_with_order_leaves_forward_dag() {
+        let mut graph = DiGraph::new();
+        for i in 0..6 {
+            graph.add_node(&format!("n{}", i));
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 2);
+        graph.add_edge(4, 5);
+        graph.add_edge(5, 3);
+
+        let (arcs, order) = feedback_arc_set_with_order(&graph);
+        assert!(is_acyclic_after_removal(&graph, &arcs));
+
+        let mut position = vec![0usize; order.len()];
+        for (pos, &v) in order.iter().enumerate() {
+            position[v] = pos;
+        }
+        let arc_set: std::collections::HashSet<EdgeId> = arcs.into_iter().collect();
+        for u in 0..graph.len() {
+            for &v in graph.successors_slice(u) {
+                if !arc_set.contains(&(u, v)) {
+                    assert!(
+                        position[u] < position[v],
+                        "non-feedback edge ({}, {}) should point forward in the order",
+                        u,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    // ========================================================================
+    // Back-Edge / Decycle-by-Reversal Tests
+    // ========================================================================
+
+    #[test]
+    fn test_back_edges_dag_is_empty() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        assert!(back_edges(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_back_edges_simple_cycle() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let edges = back_edges(&graph);
+        assert_eq!(edges, vec![(c, a)]);
+    }
+
+    #[test]
+    fn test_back_edges_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        graph.add_edge(a, a);
+
+        assert_eq!(back_edges(&graph), vec![(a, a)]);
+    }
+
+    #[test]
+    fn test_decycle_by_reversal_is_acyclic() {
+        // a -> b -> c -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let decycled = decycle_by_reversal(&graph);
+        assert!(!has_cycles(&decycled));
+        assert_eq!(decycled.len(), graph.len());
+    }
+
+    #[test]
+    fn test_decycle_by_reversal_preserves_node_ids() {
+        let mut graph = DiGraph::new();
+        graph.add_node("issue-a");
+        graph.add_node("issue-b");
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+
+        let decycled = decycle_by_reversal(&graph);
+        assert_eq!(decycled.node_id(0), Some("issue-a".to_string()));
+        assert_eq!(decycled.node_id(1), Some("issue-b".to_string()));
+    }
+
+    #[test]
+    fn test_decycle_by_reversal_preserves_edge_count() {
+        // a -> b -> c -> a, plus a -> c (non-back edge stays forward)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+        graph.add_edge(a, c);
+
+        let decycled = decycle_by_reversal(&graph);
+        let edge_count: usize = (0..decycled.len())
+            .map(|v| decycled.successors_slice(v).len())
+            .sum();
+        assert_eq!(edge_count, 4);
+        assert!(!has_cycles(&decycled));
+    }
+
+    // ========================================================================
+    // Cycle Basis Tests
+    // ========================================================================
+
+    #[test]
+    fn test_cycle_basis_dag_is_empty() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        assert!(cycle_basis(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_cycle_basis_simple_cycle() {
+        // a -> b -> a: 2 edges, 2 nodes, 1 component => 1 basis cycle
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let basis = cycle_basis(&graph);
+        assert_eq!(basis.len(), 1);
+    }
+
+    #[test]
+    fn test_cycle_basis_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        graph.add_edge(a, a);
+
+        let basis = cycle_basis(&graph);
+        assert_eq!(basis, vec![vec![a]]);
+    }
+
+    #[test]
+    fn test_cycle_basis_count_matches_formula() {
+        // Diamond with a back edge: 5 edges, 4 nodes, 1 component => 2 basis cycles
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     d -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+        graph.add_edge(d, a);
+
+        let basis = cycle_basis(&graph);
+        // |E| - |V| + components = 5 - 4 + 1 = 2
+        assert_eq!(basis.len(), 2);
+    }
+
+    #[test]
+    fn test_cycle_basis_disconnected_components() {
+        // Two separate cycles: a<->b, c<->d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(c, d);
+        graph.add_edge(d, c);
+
+        let basis = cycle_basis(&graph);
+        // |E| - |V| + components = 4 - 4 + 2 = 2
+        assert_eq!(basis.len(), 2);
+    }
 }
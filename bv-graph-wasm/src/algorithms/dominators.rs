@@ -0,0 +1,302 @@
+//! Dominator tree analysis.
+//!
+//! Computes immediate dominators over a `DiGraph` rooted at a chosen start
+//! node using the iterative Cooper-Harvey-Kennedy algorithm. A node `d`
+//! dominates `v` if every path from the root to `v` passes through `d`,
+//! which lets the viewer answer "which single issue, if unblocked, frees
+//! the largest downstream subtree" — something cycle-only analysis can't
+//! express.
+
+use crate::graph::DiGraph;
+
+/// Immediate-dominator map computed from a root node.
+pub struct DominatorTree {
+    root: usize,
+    idom: Vec<Option<usize>>,
+}
+
+impl DominatorTree {
+    /// The immediate dominator of `node`, or `None` if `node` is the root
+    /// or unreachable from it.
+    pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+        self.idom.get(node).copied().flatten()
+    }
+
+    /// The dominator chain from `node` up to and including the root, or an
+    /// empty vector if `node` is unreachable from the root.
+    pub fn dominator_chain(&self, node: usize) -> Vec<usize> {
+        if node != self.root && self.immediate_dominator(node).is_none() {
+            return Vec::new();
+        }
+
+        let mut chain = vec![node];
+        let mut current = node;
+        while current != self.root {
+            match self.idom[current] {
+                Some(d) => {
+                    chain.push(d);
+                    current = d;
+                }
+                None => break,
+            }
+        }
+        chain
+    }
+
+    /// Export the dominator relationship itself as a `DiGraph`: one node per
+    /// node of `graph` (same ids and labels), with an edge from each node to
+    /// every node it immediately dominates. Unreachable nodes keep their id
+    /// but end up with no incoming edge, so the viewer can render
+    /// "must-pass-through" chains the same way it renders any other graph.
+    pub fn as_digraph(&self, graph: &DiGraph) -> DiGraph {
+        let n = self.idom.len();
+        let mut tree = DiGraph::new();
+        for v in 0..n {
+            let label = graph.node_id(v).unwrap_or_else(|| v.to_string());
+            tree.add_node(&label);
+        }
+        for v in 0..n {
+            if let Some(d) = self.idom[v] {
+                tree.add_edge(d, v);
+            }
+        }
+        tree
+    }
+}
+
+/// Compute the dominator tree of `graph` rooted at `root`.
+///
+/// Uses the iterative Cooper-Harvey-Kennedy formulation: process nodes in
+/// reverse postorder, and for each node intersect the idom candidates of
+/// its already-processed predecessors via a two-finger walk up the
+/// partially-built dominator tree (by postorder number) until they meet,
+/// iterating to a fixpoint.
+pub fn dominator_tree(graph: &DiGraph, root: usize) -> DominatorTree {
+    let n = graph.len();
+    let (rpo, post_index) = reverse_postorder(graph, root, n);
+
+    let mut idom: Vec<Option<usize>> = vec![None; n];
+    idom[root] = Some(root);
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        // rpo[0] is always the root; only visit the rest.
+        for &v in rpo.iter().skip(1) {
+            let mut new_idom: Option<usize> = None;
+            for &p in graph.predecessors_slice(v) {
+                if idom[p].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(candidate) => intersect(candidate, p, &idom, &post_index),
+                });
+            }
+            if let Some(new_idom) = new_idom {
+                if idom[v] != Some(new_idom) {
+                    idom[v] = Some(new_idom);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    idom[root] = None; // the root has no dominator
+    DominatorTree { root, idom }
+}
+
+/// Walk two idom candidates up the partially-built dominator tree until
+/// they meet, using postorder numbers to decide which finger to advance.
+fn intersect(mut a: usize, mut b: usize, idom: &[Option<usize>], post_index: &[usize]) -> usize {
+    while a != b {
+        while post_index[a] < post_index[b] {
+            a = idom[a].expect("finger walk only visits already-dominated nodes");
+        }
+        while post_index[b] < post_index[a] {
+            b = idom[b].expect("finger walk only visits already-dominated nodes");
+        }
+    }
+    a
+}
+
+/// Reverse postorder of nodes reachable from `root`, plus a postorder-index
+/// lookup where a larger number means "encountered earlier" (the root gets
+/// the largest index), as required by [`intersect`]'s two-finger walk.
+fn reverse_postorder(graph: &DiGraph, root: usize, n: usize) -> (Vec<usize>, Vec<usize>) {
+    struct Frame {
+        node: usize,
+        succ_idx: usize,
+    }
+
+    let mut visited = vec![false; n];
+    let mut postorder = Vec::with_capacity(n);
+    let mut stack = vec![Frame {
+        node: root,
+        succ_idx: 0,
+    }];
+    visited[root] = true;
+
+    while let Some(frame) = stack.last_mut() {
+        let v = frame.node;
+        let successors = graph.successors_slice(v);
+        if frame.succ_idx < successors.len() {
+            let w = successors[frame.succ_idx];
+            frame.succ_idx += 1;
+            if !visited[w] {
+                visited[w] = true;
+                stack.push(Frame {
+                    node: w,
+                    succ_idx: 0,
+                });
+            }
+        } else {
+            postorder.push(v);
+            stack.pop();
+        }
+    }
+
+    postorder.reverse();
+    let mut post_index = vec![0usize; n];
+    for (i, &v) in postorder.iter().enumerate() {
+        post_index[v] = postorder.len() - i;
+    }
+
+    (postorder, post_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominators_linear_chain() {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let dom = dominator_tree(&graph, a);
+        assert_eq!(dom.immediate_dominator(a), None);
+        assert_eq!(dom.immediate_dominator(b), Some(a));
+        assert_eq!(dom.immediate_dominator(c), Some(b));
+        assert_eq!(dom.dominator_chain(c), vec![c, b, a]);
+    }
+
+    #[test]
+    fn test_dominators_diamond() {
+        //     a
+        //    / \
+        //   b   c
+        //    \ /
+        //     d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let dom = dominator_tree(&graph, a);
+        // Two paths reach d, so only a dominates it, not b or c.
+        assert_eq!(dom.immediate_dominator(d), Some(a));
+        assert_eq!(dom.immediate_dominator(b), Some(a));
+        assert_eq!(dom.immediate_dominator(c), Some(a));
+    }
+
+    #[test]
+    fn test_dominators_keystone_bottleneck() {
+        // a -> b -> c, and b -> d, b -> e: b is a keystone dominating c,d,e
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(b, d);
+        graph.add_edge(b, e);
+
+        let dom = dominator_tree(&graph, a);
+        assert_eq!(dom.immediate_dominator(c), Some(b));
+        assert_eq!(dom.immediate_dominator(d), Some(b));
+        assert_eq!(dom.immediate_dominator(e), Some(b));
+    }
+
+    #[test]
+    fn test_dominators_unreachable_node_has_none() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_node("unreachable");
+        graph.add_edge(a, b);
+
+        let dom = dominator_tree(&graph, a);
+        assert_eq!(dom.immediate_dominator(2), None);
+        assert!(dom.dominator_chain(2).is_empty());
+    }
+
+    #[test]
+    fn test_dominators_cyclic_graph() {
+        // a -> b -> c -> b (cycle not containing the root)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, b);
+
+        let dom = dominator_tree(&graph, a);
+        assert_eq!(dom.immediate_dominator(b), Some(a));
+        assert_eq!(dom.immediate_dominator(c), Some(b));
+    }
+
+    #[test]
+    fn test_dominators_as_digraph_keystone_bottleneck() {
+        // a -> b -> c, and b -> d, b -> e: tree should be a -> b -> {c, d, e}
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(b, d);
+        graph.add_edge(b, e);
+
+        let dom = dominator_tree(&graph, a);
+        let tree = dom.as_digraph(&graph);
+
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.successors_slice(a), &[b]);
+        let mut children = tree.successors_slice(b).to_vec();
+        children.sort();
+        assert_eq!(children, vec![c, d, e]);
+        assert!(tree.successors_slice(c).is_empty());
+    }
+
+    #[test]
+    fn test_dominators_as_digraph_unreachable_node_isolated() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_node("unreachable");
+        graph.add_edge(a, b);
+
+        let dom = dominator_tree(&graph, a);
+        let tree = dom.as_digraph(&graph);
+
+        assert_eq!(tree.len(), 3);
+        assert!(tree.predecessors_slice(2).is_empty());
+        assert!(tree.successors_slice(2).is_empty());
+    }
+}
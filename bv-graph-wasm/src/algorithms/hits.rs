@@ -10,12 +10,25 @@
 use crate::graph::DiGraph;
 use serde::Serialize;
 
+/// Normalization scheme applied to hub/authority scores each iteration.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HitsNorm {
+    /// Unit L2 (Euclidean) norm. The default; numerically stable for iteration.
+    L2,
+    /// L1 norm: scores sum to 1, handy for reporting as fractions of a whole.
+    L1,
+    /// Max norm: the largest component becomes 1.0, the rest scaled relative to it.
+    Max,
+}
+
 /// Configuration for HITS computation.
 pub struct HITSConfig {
     /// Convergence tolerance
     pub tolerance: f64,
     /// Maximum iterations
     pub max_iterations: u32,
+    /// Normalization applied to hub/authority scores each iteration
+    pub norm: HitsNorm,
 }
 
 impl Default for HITSConfig {
@@ -23,11 +36,16 @@ impl Default for HITSConfig {
         HITSConfig {
             tolerance: 1e-6,
             max_iterations: 100,
+            norm: HitsNorm::L2,
         }
     }
 }
 
 /// Result of HITS computation.
+///
+/// Scores are normalized per `HITSConfig::norm` (L2 unless configured
+/// otherwise), so compare `hubs`/`authorities` across calls only when they
+/// used the same norm.
 #[derive(Serialize)]
 pub struct HITSResult {
     /// Hub scores (nodes that point to authorities)
@@ -88,9 +106,9 @@ pub fn hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
             }
         }
 
-        // Normalize both vectors (L2 norm for stability)
-        normalize_l2(&mut new_auth);
-        normalize_l2(&mut new_hubs);
+        // Normalize both vectors per the configured norm
+        normalize(&mut new_auth, config.norm);
+        normalize(&mut new_hubs, config.norm);
 
         // Check convergence
         let auth_diff: f64 = auth
@@ -124,6 +142,15 @@ pub fn hits_default(graph: &DiGraph) -> HITSResult {
     hits(graph, &HITSConfig::default())
 }
 
+/// Normalize a vector according to the configured `HitsNorm`.
+fn normalize(vec: &mut [f64], norm: HitsNorm) {
+    match norm {
+        HitsNorm::L2 => normalize_l2(vec),
+        HitsNorm::L1 => normalize_l1(vec),
+        HitsNorm::Max => normalize_max(vec),
+    }
+}
+
 /// Normalize vector to unit L2 norm.
 fn normalize_l2(vec: &mut [f64]) {
     let norm: f64 = vec.iter().map(|v| v * v).sum::<f64>().sqrt();
@@ -134,6 +161,134 @@ fn normalize_l2(vec: &mut [f64]) {
     }
 }
 
+/// Normalize vector so its components sum to 1 (sum of absolute values).
+fn normalize_l1(vec: &mut [f64]) {
+    let sum: f64 = vec.iter().map(|v| v.abs()).sum();
+    if sum > 0.0 {
+        for v in vec.iter_mut() {
+            *v /= sum;
+        }
+    }
+}
+
+/// Normalize vector so its largest component becomes 1.0.
+fn normalize_max(vec: &mut [f64]) {
+    let max = vec.iter().cloned().fold(0.0, f64::max);
+    if max > 0.0 {
+        for v in vec.iter_mut() {
+            *v /= max;
+        }
+    }
+}
+
+/// Compute HITS hub and authority scores, parallelizing each iteration with
+/// rayon when the `rayon` feature is enabled.
+///
+/// Each per-node update (authority from predecessors, hub from successors)
+/// is independent across target nodes, so it parallelizes cleanly over
+/// `0..n`. Produces the same scores as [`hits`] for the same input, up to
+/// floating-point rounding from the parallel sum reductions; use
+/// this entry point for large issue graphs where the serial scatter/gather
+/// becomes a bottleneck.
+#[cfg(feature = "rayon")]
+pub fn parallel_hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
+    use rayon::prelude::*;
+
+    let n = graph.len();
+    if n == 0 {
+        return HITSResult {
+            hubs: Vec::new(),
+            authorities: Vec::new(),
+            iterations: 0,
+        };
+    }
+
+    let mut hubs = vec![1.0 / (n as f64); n];
+    let mut auth = vec![1.0 / (n as f64); n];
+
+    let mut iterations = 0;
+
+    for iter in 0..config.max_iterations {
+        iterations = iter + 1;
+
+        let mut new_auth = vec![0.0; n];
+        new_auth.par_iter_mut().enumerate().for_each(|(v, slot)| {
+            *slot = graph.predecessors_slice(v).iter().map(|&u| hubs[u]).sum();
+        });
+
+        let mut new_hubs = vec![0.0; n];
+        new_hubs.par_iter_mut().enumerate().for_each(|(u, slot)| {
+            *slot = graph
+                .successors_slice(u)
+                .iter()
+                .map(|&v| new_auth[v])
+                .sum();
+        });
+
+        normalize_parallel(&mut new_auth, config.norm);
+        normalize_parallel(&mut new_hubs, config.norm);
+
+        let auth_diff: f64 = auth
+            .par_iter()
+            .zip(new_auth.par_iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+        let hub_diff: f64 = hubs
+            .par_iter()
+            .zip(new_hubs.par_iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+
+        auth = new_auth;
+        hubs = new_hubs;
+
+        if auth_diff + hub_diff < config.tolerance {
+            break;
+        }
+    }
+
+    HITSResult {
+        hubs,
+        authorities: auth,
+        iterations,
+    }
+}
+
+/// Compute HITS hub and authority scores (serial fallback for builds without
+/// the `rayon` feature). See [`hits`] for the algorithm.
+#[cfg(not(feature = "rayon"))]
+pub fn parallel_hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
+    hits(graph, config)
+}
+
+/// Normalize a vector according to the configured `HitsNorm`, using a
+/// parallel reduction for the norm computation.
+#[cfg(feature = "rayon")]
+fn normalize_parallel(vec: &mut [f64], norm: HitsNorm) {
+    use rayon::prelude::*;
+
+    match norm {
+        HitsNorm::L2 => {
+            let magnitude: f64 = vec.par_iter().map(|v| v * v).sum::<f64>().sqrt();
+            if magnitude > 0.0 {
+                vec.par_iter_mut().for_each(|v| *v /= magnitude);
+            }
+        }
+        HitsNorm::L1 => {
+            let sum: f64 = vec.par_iter().map(|v| v.abs()).sum();
+            if sum > 0.0 {
+                vec.par_iter_mut().for_each(|v| *v /= sum);
+            }
+        }
+        HitsNorm::Max => {
+            let max = vec.par_iter().cloned().reduce(|| 0.0, f64::max);
+            if max > 0.0 {
+                vec.par_iter_mut().for_each(|v| *v /= max);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,4 +493,105 @@ mod tests {
             "Hub nodes should have higher hub scores"
         );
     }
+
+    // === Parallel HITS Tests ===
+
+    #[test]
+    fn test_parallel_hits_matches_serial() {
+        // a -> b -> c -> a, a non-trivial but small graph
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let config = HITSConfig::default();
+        let serial = hits(&graph, &config);
+        let parallel = parallel_hits(&graph, &config);
+
+        assert_eq!(serial.iterations, parallel.iterations);
+        for i in 0..3 {
+            assert!((serial.hubs[i] - parallel.hubs[i]).abs() < 1e-9);
+            assert!((serial.authorities[i] - parallel.authorities[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_parallel_hits_empty() {
+        let graph = DiGraph::new();
+        let result = parallel_hits(&graph, &HITSConfig::default());
+        assert!(result.hubs.is_empty());
+        assert!(result.authorities.is_empty());
+    }
+
+    // === Configurable Norm Tests ===
+
+    #[test]
+    fn test_hits_l1_norm_sums_to_one() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let config = HITSConfig {
+            norm: HitsNorm::L1,
+            ..HITSConfig::default()
+        };
+        let result = hits(&graph, &config);
+
+        let hub_sum: f64 = result.hubs.iter().sum();
+        let auth_sum: f64 = result.authorities.iter().sum();
+        assert!((hub_sum - 1.0).abs() < 0.001, "hub scores should sum to 1");
+        assert!(
+            (auth_sum - 1.0).abs() < 0.001,
+            "authority scores should sum to 1"
+        );
+    }
+
+    #[test]
+    fn test_hits_max_norm_top_score_is_one() {
+        // hub -> a, hub -> b, hub -> c: hub should end up with the top hub score
+        let mut graph = DiGraph::new();
+        let hub = graph.add_node("hub");
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(hub, a);
+        graph.add_edge(hub, b);
+        graph.add_edge(hub, c);
+
+        let config = HITSConfig {
+            norm: HitsNorm::Max,
+            ..HITSConfig::default()
+        };
+        let result = hits(&graph, &config);
+
+        let max_hub = result.hubs.iter().cloned().fold(0.0, f64::max);
+        assert!((max_hub - 1.0).abs() < 0.001, "top hub score should be 1.0");
+    }
+
+    #[test]
+    fn test_hits_norm_does_not_affect_convergence() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        for norm in [HitsNorm::L2, HitsNorm::L1, HitsNorm::Max] {
+            let config = HITSConfig {
+                norm,
+                ..HITSConfig::default()
+            };
+            let result = hits(&graph, &config);
+            assert!(result.iterations < config.max_iterations);
+        }
+    }
 }
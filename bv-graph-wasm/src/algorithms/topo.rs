@@ -0,0 +1,200 @@
+//! Topological ordering and rank assignment.
+//!
+//! Provides Kahn's algorithm for topological sort plus a longest-path
+//! layering on top of it, used to lay out issues by dependency depth.
+
+use crate::graph::DiGraph;
+
+/// Topologically sort the graph using Kahn's algorithm.
+///
+/// # Returns
+/// `Ok(order)` with nodes in dependency order on success, or `Err(blocked)`
+/// with the nodes that could not be ordered (the cyclic remainder) if the
+/// graph is not a DAG.
+pub fn topological_sort(graph: &DiGraph) -> Result<Vec<usize>, Vec<usize>> {
+    let n = graph.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut in_degree: Vec<usize> = (0..n).map(|v| graph.predecessors_slice(v).len()).collect();
+    let mut queue: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+
+    let mut head = 0;
+    while head < queue.len() {
+        let v = queue[head];
+        head += 1;
+        order.push(v);
+
+        for &w in graph.successors_slice(v) {
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                queue.push(w);
+            }
+        }
+    }
+
+    if order.len() == n {
+        Ok(order)
+    } else {
+        let ordered: std::collections::HashSet<usize> = order.into_iter().collect();
+        let blocked: Vec<usize> = (0..n).filter(|v| !ordered.contains(v)).collect();
+        Err(blocked)
+    }
+}
+
+/// Assign each node a rank (longest-path layer) for acyclic graphs.
+///
+/// `rank[v] = 1 + max(rank of predecessors)`; roots get rank 1. Returns all
+/// zeros if the graph has a cycle — callers should run a feedback-arc-set
+/// pass first to obtain a DAG before ranking.
+pub fn assign_ranks(graph: &DiGraph) -> Vec<usize> {
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let order = match topological_sort(graph) {
+        Ok(o) => o,
+        Err(_) => return vec![0; n],
+    };
+
+    let mut ranks = vec![0usize; n];
+    for &v in &order {
+        let max_pred_rank = graph
+            .predecessors_slice(v)
+            .iter()
+            .map(|&u| ranks[u])
+            .max()
+            .unwrap_or(0);
+        ranks[v] = 1 + max_pred_rank;
+    }
+
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topo_empty() {
+        let graph = DiGraph::new();
+        assert_eq!(topological_sort(&graph), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_topo_chain() {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let order = topological_sort(&graph).unwrap();
+        assert_eq!(order, vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_topo_diamond() {
+        // a -> b, a -> c, b -> d, c -> d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let order = topological_sort(&graph).unwrap();
+        let pos: std::collections::HashMap<usize, usize> =
+            order.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+        assert!(pos[&a] < pos[&b]);
+        assert!(pos[&a] < pos[&c]);
+        assert!(pos[&b] < pos[&d]);
+        assert!(pos[&c] < pos[&d]);
+    }
+
+    #[test]
+    fn test_topo_cycle_returns_blocked() {
+        // a -> b -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let result = topological_sort(&graph);
+        let blocked = result.unwrap_err();
+        let mut sorted = blocked.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![a, b]);
+    }
+
+    #[test]
+    fn test_topo_partial_cycle() {
+        // a -> b -> c -> b (b,c cyclic, a is free)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, b);
+
+        let blocked = topological_sort(&graph).unwrap_err();
+        let mut sorted = blocked.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![b, c]);
+    }
+
+    #[test]
+    fn test_assign_ranks_chain() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let ranks = assign_ranks(&graph);
+        assert_eq!(ranks[a], 1);
+        assert_eq!(ranks[b], 2);
+        assert_eq!(ranks[c], 3);
+    }
+
+    #[test]
+    fn test_assign_ranks_diamond() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let ranks = assign_ranks(&graph);
+        assert_eq!(ranks[a], 1);
+        assert_eq!(ranks[b], 2);
+        assert_eq!(ranks[c], 2);
+        assert_eq!(ranks[d], 3);
+    }
+
+    #[test]
+    fn test_assign_ranks_cyclic_is_zero() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        assert_eq!(assign_ranks(&graph), vec![0, 0]);
+    }
+}